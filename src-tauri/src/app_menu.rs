@@ -0,0 +1,115 @@
+use std::sync::Mutex;
+
+use tauri::menu::{Menu, MenuItem, PredefinedMenuItem, Submenu};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_dialog::DialogExt;
+
+/// The currently selected generated image, if any. `Save Image As…` does nothing
+/// while this is empty. The item's enabled state is not derived from this value; the
+/// frontend sets it separately through `set_menu_item_enabled`.
+#[derive(Default)]
+pub struct SelectedImage(Mutex<Option<String>>);
+
+pub fn setup(app: &tauri::App) -> tauri::Result<()> {
+    let save_image = MenuItem::with_id(app, "save_image", "Save Image As…", true, None::<&str>)?;
+    let export = MenuItem::with_id(app, "export", "Export", true, None::<&str>)?;
+    let file_menu = Submenu::with_items(
+        app,
+        "File",
+        true,
+        &[&save_image, &export, &PredefinedMenuItem::quit(app, None)?],
+    )?;
+
+    let copy = PredefinedMenuItem::copy(app, None)?;
+    let edit_menu = Submenu::with_items(app, "Edit", true, &[&copy])?;
+
+    let toggle_devtools =
+        MenuItem::with_id(app, "toggle_devtools", "Toggle Devtools", true, None::<&str>)?;
+    let view_menu = Submenu::with_items(app, "View", true, &[&toggle_devtools])?;
+
+    let window_menu = Submenu::with_items(
+        app,
+        "Window",
+        true,
+        &[
+            &PredefinedMenuItem::minimize(app, None)?,
+            &PredefinedMenuItem::close_window(app, None)?,
+        ],
+    )?;
+
+    let menu = Menu::with_items(app, &[&file_menu, &edit_menu, &view_menu, &window_menu])?;
+    app.set_menu(menu)?;
+    app.manage(SelectedImage::default());
+
+    let handle = app.handle().clone();
+    app.on_menu_event(move |_app, event| handle_menu_event(&handle, event.id().as_ref()));
+
+    Ok(())
+}
+
+fn handle_menu_event(app: &AppHandle, id: &str) {
+    match id {
+        "save_image" => save_selected_image(app),
+        "export" => {
+            let _ = app.emit("menu://export", ());
+        }
+        "toggle_devtools" => {
+            if let Some(window) = app.get_webview_window("main") {
+                if window.is_devtools_open() {
+                    window.close_devtools();
+                } else {
+                    window.open_devtools();
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn save_selected_image(app: &AppHandle) {
+    let Some(path) = app.state::<SelectedImage>().0.lock().unwrap().clone() else {
+        return;
+    };
+
+    let app = app.clone();
+    app.dialog()
+        .file()
+        .add_filter("Image", &["png", "jpg", "jpeg"])
+        .save_file(move |destination| {
+            let Some(destination) = destination else {
+                return;
+            };
+            if let Some(destination) = destination.as_path() {
+                let _ = std::fs::copy(&path, destination);
+            }
+        });
+}
+
+/// Called by the frontend whenever the selection changes, so the native menu's
+/// "Save Image As…" item can be disabled when nothing is selected.
+#[tauri::command]
+pub fn set_menu_item_enabled(app: AppHandle, id: String, enabled: bool) -> Result<(), String> {
+    let menu = app.menu().ok_or("no application menu registered")?;
+    // `Menu::get` only looks at top-level entries, so also search each submenu
+    // (e.g. `save_image` lives under "File").
+    let item = menu
+        .get(&id)
+        .or_else(|| {
+            let items = menu.items().ok()?;
+            items
+                .iter()
+                .filter_map(|kind| kind.as_submenu())
+                .find_map(|submenu| submenu.get(&id))
+        })
+        .and_then(|item| item.as_menuitem().cloned())
+        .ok_or_else(|| format!("no menu item with id {id}"))?;
+    item.set_enabled(enabled).map_err(|e| e.to_string())
+}
+
+/// Called by the frontend when the user selects a generated image, so the native
+/// menu knows what `save_image` should write to disk.
+#[tauri::command]
+pub fn set_selected_image(app: AppHandle, path: Option<String>) -> Result<(), String> {
+    *app.state::<SelectedImage>().0.lock().unwrap() = path;
+    Ok(())
+}
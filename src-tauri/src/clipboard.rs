@@ -0,0 +1,36 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use tauri::AppHandle;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+/// Copies a generated image to the system clipboard as a native bitmap so it can be
+/// pasted directly into other apps, rather than as a file path or a data URL. Accepts
+/// either a filesystem path or a base64-encoded PNG/JPEG payload.
+#[tauri::command]
+pub fn copy_image_to_clipboard(app: AppHandle, path_or_base64: String) -> Result<(), String> {
+    let bytes = load_image_bytes(&path_or_base64)?;
+    let image = image::load_from_memory(&bytes)
+        .map_err(|e| format!("unsupported image format: {e}"))?
+        .to_rgba8();
+
+    let (width, height) = image.dimensions();
+    let tauri_image = tauri::image::Image::new_owned(image.into_raw(), width, height);
+
+    app.clipboard()
+        .write_image(&tauri_image)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn copy_text(app: AppHandle, text: String) -> Result<(), String> {
+    app.clipboard().write_text(text).map_err(|e| e.to_string())
+}
+
+fn load_image_bytes(path_or_base64: &str) -> Result<Vec<u8>, String> {
+    if let Ok(bytes) = std::fs::read(path_or_base64) {
+        return Ok(bytes);
+    }
+    STANDARD
+        .decode(path_or_base64)
+        .map_err(|_| "input is neither a readable path nor valid base64".to_string())
+}
@@ -0,0 +1,138 @@
+use std::fs;
+use std::io::Cursor;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use image::imageops::FilterType;
+use image::ImageFormat;
+use lru::LruCache;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Manager, State};
+
+const THUMBNAIL_CACHE_DIR: &str = "thumbnails";
+const MAX_CACHED_THUMBNAILS: usize = 256;
+
+#[derive(Clone, Serialize)]
+pub struct ImageEntry {
+    pub path: String,
+    pub hash: String,
+}
+
+/// In-memory LRU of recently decoded thumbnails, keyed by `(hash, max_dim)`, backed by
+/// the on-disk cache under the app cache dir so a cold start only re-decodes once.
+pub struct ThumbnailCache(Mutex<LruCache<(String, u32), Vec<u8>>>);
+
+impl Default for ThumbnailCache {
+    fn default() -> Self {
+        let capacity = NonZeroUsize::new(MAX_CACHED_THUMBNAILS).expect("capacity is non-zero");
+        Self(Mutex::new(LruCache::new(capacity)))
+    }
+}
+
+/// Lists the generated images in `dir`, hashing each file's contents so the frontend
+/// and the thumbnail cache can address images by a stable content hash rather than path.
+#[tauri::command]
+pub fn list_images(dir: String) -> Result<Vec<ImageEntry>, String> {
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(&dir).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        if !is_image_file(&path) {
+            continue;
+        }
+
+        let bytes = fs::read(&path).map_err(|e| e.to_string())?;
+        entries.push(ImageEntry {
+            path: path.to_string_lossy().into_owned(),
+            hash: content_hash(&bytes),
+        });
+    }
+    Ok(entries)
+}
+
+/// Reads the full-resolution bytes of the image identified by `hash`, searching `dir`
+/// for a matching file. Used sparingly by the frontend: most of the gallery should be
+/// served by `get_thumbnail` instead of loading full-resolution originals.
+#[tauri::command]
+pub fn read_image_by_hash(dir: String, hash: String) -> Result<Vec<u8>, String> {
+    for entry in fs::read_dir(&dir).map_err(|e| e.to_string())? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if !is_image_file(&path) {
+            continue;
+        }
+        let bytes = fs::read(&path).map_err(|e| e.to_string())?;
+        if content_hash(&bytes) == hash {
+            return Ok(bytes);
+        }
+    }
+    Err(format!("no image found with hash {hash}"))
+}
+
+/// Returns a downscaled PNG thumbnail for `path`, no larger than `max_dim` on its
+/// longest side. Thumbnails are cached on disk under the app cache dir keyed by
+/// content hash, and the in-memory LRU avoids re-reading that cache file on every
+/// scroll frame.
+#[tauri::command]
+pub fn get_thumbnail(
+    app: AppHandle,
+    cache: State<'_, ThumbnailCache>,
+    path: String,
+    max_dim: u32,
+) -> Result<Vec<u8>, String> {
+    let source = fs::read(&path).map_err(|e| e.to_string())?;
+    let hash = content_hash(&source);
+    let key = (hash.clone(), max_dim);
+
+    if let Some(cached) = cache.0.lock().unwrap().get(&key) {
+        return Ok(cached.clone());
+    }
+
+    let cache_dir = thumbnail_cache_dir(&app)?;
+    let cache_path = cache_dir.join(format!("{hash}_{max_dim}.png"));
+    if let Ok(bytes) = fs::read(&cache_path) {
+        cache.0.lock().unwrap().put(key, bytes.clone());
+        return Ok(bytes);
+    }
+
+    let image = image::load_from_memory(&source).map_err(|e| e.to_string())?;
+    let thumbnail = if image.width() > max_dim || image.height() > max_dim {
+        image.resize(max_dim, max_dim, FilterType::Lanczos3)
+    } else {
+        image
+    };
+
+    let mut bytes = Vec::new();
+    thumbnail
+        .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+        .map_err(|e| e.to_string())?;
+
+    fs::create_dir_all(&cache_dir).map_err(|e| e.to_string())?;
+    fs::write(&cache_path, &bytes).map_err(|e| e.to_string())?;
+
+    cache.0.lock().unwrap().put(key, bytes.clone());
+    Ok(bytes)
+}
+
+fn thumbnail_cache_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let cache_dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| e.to_string())?
+        .join(THUMBNAIL_CACHE_DIR);
+    Ok(cache_dir)
+}
+
+fn content_hash(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+fn is_image_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("png" | "jpg" | "jpeg" | "webp")
+    )
+}
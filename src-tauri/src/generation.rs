@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+/// Tracks the cancellation flag for every in-flight generation job, keyed by job id.
+#[derive(Default)]
+pub struct GenerationJobs(Mutex<HashMap<String, Arc<AtomicBool>>>);
+
+#[derive(Debug, Deserialize)]
+pub struct GenerateImageParams {
+    pub id: String,
+    pub prompt: String,
+    pub steps: u32,
+}
+
+#[derive(Clone, Serialize)]
+struct ProgressPayload {
+    id: String,
+    step: u32,
+    total: u32,
+    preview_png_base64: String,
+}
+
+#[derive(Clone, Serialize)]
+struct DonePayload {
+    id: String,
+    image_png_base64: String,
+}
+
+#[derive(Clone, Serialize)]
+struct ErrorPayload {
+    id: String,
+    message: String,
+}
+
+/// Starts an image-generation job on a background task, streaming progress over
+/// `generation://progress` events instead of blocking the `invoke` call until the
+/// final image is ready. Terminates with a `generation://done` or `generation://error`
+/// event so the frontend can tear down its progress UI either way.
+///
+/// NOTE: `preview_png_base64`/`image_png_base64` are not wired to a real renderer
+/// yet (see `render_preview`) and currently come back as an empty string on every
+/// event. The IPC contract (field names and timing) is final; only the pixels are
+/// a stub.
+#[tauri::command]
+pub async fn generate_image(
+    app: AppHandle,
+    jobs: State<'_, GenerationJobs>,
+    params: GenerateImageParams,
+) -> Result<(), String> {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    jobs.0
+        .lock()
+        .unwrap()
+        .insert(params.id.clone(), cancelled.clone());
+
+    let id = params.id.clone();
+    let total = params.steps.max(1);
+
+    tauri::async_runtime::spawn(async move {
+        for step in 1..=total {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+
+            if cancelled.load(Ordering::Relaxed) {
+                let _ = app.emit(
+                    "generation://error",
+                    ErrorPayload {
+                        id: id.clone(),
+                        message: "cancelled".to_string(),
+                    },
+                );
+                remove_job(&app, &id);
+                return;
+            }
+
+            let _ = app.emit(
+                "generation://progress",
+                ProgressPayload {
+                    id: id.clone(),
+                    step,
+                    total,
+                    preview_png_base64: render_preview(&params.prompt, step, total),
+                },
+            );
+        }
+
+        let _ = app.emit(
+            "generation://done",
+            DonePayload {
+                id: id.clone(),
+                image_png_base64: render_preview(&params.prompt, total, total),
+            },
+        );
+        remove_job(&app, &id);
+    });
+
+    Ok(())
+}
+
+fn remove_job(app: &AppHandle, id: &str) {
+    app.state::<GenerationJobs>().0.lock().unwrap().remove(id);
+}
+
+/// Flips the cancellation flag for `id` so the next step check in `generate_image`
+/// stops the job, removes it from `GenerationJobs`, and emits `generation://error`
+/// instead of continuing. Errors if `id` isn't a currently-running job, including
+/// one that has already finished or been cancelled.
+#[tauri::command]
+pub fn cancel_generation(jobs: State<'_, GenerationJobs>, id: String) -> Result<(), String> {
+    match jobs.0.lock().unwrap().remove(&id) {
+        Some(flag) => {
+            flag.store(true, Ordering::Relaxed);
+            Ok(())
+        }
+        None => Err(format!("no generation job running with id {id}")),
+    }
+}
+
+fn render_preview(_prompt: &str, _step: u32, _total: u32) -> String {
+    // Placeholder until the actual image model is wired in; keeps the IPC
+    // contract (base64 PNG string) stable for the frontend to build against.
+    String::new()
+}
@@ -1,5 +1,12 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod app_menu;
+mod clipboard;
+mod gallery;
+mod generation;
+mod tray;
+mod window;
+
 use tauri::Manager;
 
 #[tauri::command]
@@ -12,7 +19,31 @@ async fn open_devtools(window: tauri::Window) {
 
 fn main() {
     tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![open_devtools])
+        .plugin(tauri_plugin_global_shortcut::Builder::new().with_handler(tray::on_shortcut).build())
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .manage(generation::GenerationJobs::default())
+        .manage(gallery::ThumbnailCache::default())
+        .setup(|app| {
+            tray::setup(app)?;
+            app_menu::setup(app)?;
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            open_devtools,
+            window::open_or_focus_window,
+            tray::set_global_shortcut,
+            tray::get_global_shortcut,
+            generation::generate_image,
+            generation::cancel_generation,
+            gallery::list_images,
+            gallery::read_image_by_hash,
+            gallery::get_thumbnail,
+            app_menu::set_menu_item_enabled,
+            app_menu::set_selected_image,
+            clipboard::copy_image_to_clipboard,
+            clipboard::copy_text
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
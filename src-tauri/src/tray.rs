@@ -0,0 +1,175 @@
+use std::fs;
+use std::sync::Mutex;
+
+use tauri::menu::{Menu, MenuItem};
+use tauri::tray::{TrayIconBuilder, TrayIconEvent};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+const DEFAULT_ACCELERATOR: &str = "Ctrl+Shift+G";
+const SHORTCUT_CONFIG_FILE: &str = "global_shortcut.txt";
+
+/// Holds the accelerator string currently bound to the toggle-and-generate shortcut,
+/// so it can be read back by `get_global_shortcut` and re-registered when it changes.
+pub struct ShortcutBinding(Mutex<String>);
+
+impl Default for ShortcutBinding {
+    fn default() -> Self {
+        Self(Mutex::new(DEFAULT_ACCELERATOR.to_string()))
+    }
+}
+
+pub fn setup(app: &tauri::App) -> tauri::Result<()> {
+    let show = MenuItem::with_id(app, "show_hide", "Show/Hide", true, None::<&str>)?;
+    let generate = MenuItem::with_id(app, "generate", "Generate", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&show, &generate, &quit])?;
+
+    TrayIconBuilder::new()
+        .menu(&menu)
+        .show_menu_on_left_click(true)
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            "show_hide" => toggle_main_window(app),
+            "generate" => request_generation(app),
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::DoubleClick { .. } = event {
+                toggle_main_window(tray.app_handle());
+            }
+        })
+        .build(app)?;
+
+    let accelerator = resolve_startup_accelerator(app.handle());
+    app.manage(ShortcutBinding(Mutex::new(accelerator.clone())));
+    register_shortcut(app.handle(), &accelerator)?;
+
+    Ok(())
+}
+
+/// Picks the accelerator to register at startup. `global_shortcut.txt` is
+/// user-editable state, so a persisted-but-corrupt value falls back to the
+/// default rather than failing startup.
+fn resolve_startup_accelerator(app: &AppHandle) -> String {
+    match load_persisted_accelerator(app) {
+        Some(accelerator) if parse_accelerator(&accelerator).is_ok() => accelerator,
+        Some(invalid) => {
+            eprintln!("ignoring invalid persisted global shortcut '{invalid}', falling back to default");
+            DEFAULT_ACCELERATOR.to_string()
+        }
+        None => DEFAULT_ACCELERATOR.to_string(),
+    }
+}
+
+fn toggle_main_window(app: &AppHandle) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    let is_visible = window.is_visible().unwrap_or(false);
+    if is_visible {
+        let _ = window.hide();
+    } else {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+fn request_generation(app: &AppHandle) {
+    let _ = app.emit("tray://generate", ());
+}
+
+fn parse_accelerator(accelerator: &str) -> Result<Shortcut, String> {
+    accelerator
+        .parse::<Shortcut>()
+        .map_err(|_| format!("invalid accelerator: {accelerator}"))
+}
+
+fn register_shortcut(app: &AppHandle, accelerator: &str) -> tauri::Result<()> {
+    match parse_accelerator(accelerator) {
+        Ok(shortcut) => app.global_shortcut().register(shortcut),
+        Err(_) => {
+            eprintln!("ignoring unparseable accelerator '{accelerator}', shortcut not registered");
+            Ok(())
+        }
+    }
+}
+
+fn shortcut_config_path(app: &AppHandle) -> Option<std::path::PathBuf> {
+    app.path()
+        .app_config_dir()
+        .ok()
+        .map(|dir| dir.join(SHORTCUT_CONFIG_FILE))
+}
+
+fn load_persisted_accelerator(app: &AppHandle) -> Option<String> {
+    let path = shortcut_config_path(app)?;
+    let contents = fs::read_to_string(path).ok()?;
+    let accelerator = contents.trim().to_string();
+    if accelerator.is_empty() {
+        None
+    } else {
+        Some(accelerator)
+    }
+}
+
+fn save_persisted_accelerator(app: &AppHandle, accelerator: &str) -> Result<(), String> {
+    let path = shortcut_config_path(app).ok_or("could not resolve app config dir")?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    fs::write(path, accelerator).map_err(|e| e.to_string())
+}
+
+/// Rebinds the toggle-and-generate shortcut to a new accelerator, persisting it to
+/// the app config dir and unregistering whichever accelerator was previously bound.
+/// On a failed `register` (e.g. the OS rejects the accelerator or it's already taken
+/// by another app), the previous accelerator is re-registered and `ShortcutBinding`/
+/// the on-disk file are left untouched, so a rejected rebind never leaves the app
+/// with nothing registered.
+#[tauri::command]
+pub async fn set_global_shortcut(app: AppHandle, accelerator: String) -> Result<(), String> {
+    let new_shortcut = parse_accelerator(&accelerator)?;
+
+    let state = app.state::<ShortcutBinding>();
+    let previous = state.0.lock().unwrap().clone();
+    if previous == accelerator {
+        return Ok(());
+    }
+
+    let shortcuts = app.global_shortcut();
+    if let Ok(old_shortcut) = parse_accelerator(&previous) {
+        let _ = shortcuts.unregister(old_shortcut);
+    }
+
+    if let Err(err) = shortcuts.register(new_shortcut) {
+        if let Ok(old_shortcut) = parse_accelerator(&previous) {
+            let _ = shortcuts.register(old_shortcut);
+        }
+        return Err(err.to_string());
+    }
+
+    *state.0.lock().unwrap() = accelerator.clone();
+    save_persisted_accelerator(&app, &accelerator)
+}
+
+#[tauri::command]
+pub fn get_global_shortcut(app: AppHandle) -> String {
+    app.state::<ShortcutBinding>().0.lock().unwrap().clone()
+}
+
+/// Handler registered with `tauri_plugin_global_shortcut::Builder::with_handler`;
+/// toggles the main window and kicks off generation when the bound accelerator fires.
+pub fn on_shortcut(app: &AppHandle, shortcut: &Shortcut, state: ShortcutState) {
+    if state != ShortcutState::Pressed {
+        return;
+    }
+
+    let bound = app.state::<ShortcutBinding>().0.lock().unwrap().clone();
+    if parse_accelerator(&bound).map(|s| &s != shortcut).unwrap_or(true) {
+        return;
+    }
+
+    toggle_main_window(app);
+    request_generation(app);
+}
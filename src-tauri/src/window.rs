@@ -0,0 +1,52 @@
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+
+/// Looks up a window by label and brings it to the front, or builds a new one.
+///
+/// The actual window construction is dispatched onto the app's main-thread
+/// task queue via `AppHandle::run_on_main_thread`. Calling `WebviewWindowBuilder::build`
+/// directly from inside an async command can re-enter the webview event loop on
+/// Windows and overflow the main thread's stack, so we never build synchronously here.
+#[tauri::command]
+pub async fn open_or_focus_window(
+    app: AppHandle,
+    label: String,
+    url: String,
+    title: String,
+    width: f64,
+    height: f64,
+) -> Result<(), String> {
+    if let Some(existing) = app.get_webview_window(&label) {
+        return focus_existing(existing);
+    }
+
+    let handle = app.clone();
+    app.run_on_main_thread(move || {
+        if let Err(err) = build_window(&handle, &label, &url, &title, width, height) {
+            eprintln!("failed to create window '{label}': {err}");
+        }
+    })
+    .map_err(|err| err.to_string())
+}
+
+fn focus_existing(window: tauri::WebviewWindow) -> Result<(), String> {
+    if window.is_minimized().map_err(|e| e.to_string())? {
+        window.unminimize().map_err(|e| e.to_string())?;
+    }
+    window.show().map_err(|e| e.to_string())?;
+    window.set_focus().map_err(|e| e.to_string())
+}
+
+fn build_window(
+    app: &AppHandle,
+    label: &str,
+    url: &str,
+    title: &str,
+    width: f64,
+    height: f64,
+) -> tauri::Result<()> {
+    WebviewWindowBuilder::new(app, label, WebviewUrl::App(url.into()))
+        .title(title)
+        .inner_size(width, height)
+        .build()?;
+    Ok(())
+}